@@ -26,12 +26,61 @@ macro_rules! log {
     }
 }
 
+// Default rule is Conway's Life: B3/S23.
+const DEFAULT_BIRTH: u16 = 0b0000_1000;
+const DEFAULT_SURVIVAL: u16 = 0b0000_1100;
+
+// Bit-sliced full/half adders used by the SWAR fast path in `Universe::tick_swar`.
+// Each `u32` is a "plane": bit `k` of the plane is one binary digit of cell k's
+// running sum, so a 2-plane (sum, carry) pair holds values 0..=3 for every one
+// of the 32 cells packed into the word, computed with no per-cell branching.
+fn add2_bits(a: u32, b: u32) -> (u32, u32) {
+    (a ^ b, a & b)
+}
+
+fn add3_bits(a: u32, b: u32, c: u32) -> (u32, u32) {
+    (a ^ b ^ c, (a & b) | (a & c) | (b & c))
+}
+
+// Ripple-carry-adds two little-endian bit-plane numbers (plane 0 = LSB).
+fn add_planes(a: &[u32], b: &[u32]) -> Vec<u32> {
+    let len = a.len().max(b.len());
+    let mut out = Vec::with_capacity(len + 1);
+    let mut carry = 0u32;
+    for i in 0..len {
+        let x = a.get(i).copied().unwrap_or(0);
+        let y = b.get(i).copied().unwrap_or(0);
+        out.push(x ^ y ^ carry);
+        carry = (x & y) | (x & carry) | (y & carry);
+    }
+    out.push(carry);
+    out
+}
+
+// Per-cell bitmask of which of the 32 packed cells hold exactly `value` across
+// the given bit planes.
+fn eq_value(planes: &[u32], value: u8) -> u32 {
+    let mut mask = !0u32;
+    for (i, &plane) in planes.iter().enumerate() {
+        mask &= if (value >> i) & 1 == 1 { plane } else { !plane };
+    }
+    mask
+}
+
 #[wasm_bindgen]
 pub struct Universe {
     width: u32,
     height: u32,
     cells: [FixedBitSet; 2],
     i: usize,
+    birth: u16,
+    survival: u16,
+    auto_grow: bool,
+    origin_row: i32,
+    origin_col: i32,
+    ages: Vec<u32>,
+    state_count: u16,
+    states: [Vec<u8>; 2],
 }
 
 #[wasm_bindgen]
@@ -48,6 +97,16 @@ impl Universe {
         self.cells[self.i].as_slice().as_ptr()
     }
 
+    /// How many consecutive generations each cell has been alive.
+    pub fn ages(&self) -> *const u32 {
+        self.ages.as_ptr()
+    }
+
+    /// The per-cell Generations state buffer (see `set_generations_rule`).
+    pub fn states(&self) -> *const u8 {
+        self.states[self.i].as_ptr()
+    }
+
     fn get_index(&self, row: u32, column: u32) -> usize {
         (row * self.width + column) as usize
     }
@@ -61,6 +120,13 @@ impl Universe {
             Universe::new_cells(width, self.height),
             Universe::new_cells(width, self.height)
         ];
+        self.ages = vec![0; (width * self.height) as usize];
+        if self.state_count > 2 {
+            let len = (width * self.height) as usize;
+            self.states = [vec![0; len], vec![0; len]];
+        }
+        self.origin_row = 0;
+        self.origin_col = 0;
     }
 
     /// Set the height of the universe.
@@ -72,97 +138,417 @@ impl Universe {
             Universe::new_cells(self.width, height),
             Universe::new_cells(self.width, height)
         ];
+        self.ages = vec![0; (self.width * height) as usize];
+        if self.state_count > 2 {
+            let len = (self.width * height) as usize;
+            self.states = [vec![0; len], vec![0; len]];
+        }
+        self.origin_row = 0;
+        self.origin_col = 0;
     }
 
     pub fn toggle_cell(&mut self, row: u32, column: u32) {
         let idx = self.get_index(row, column);
-        self.cells[self.i].toggle(idx);
+        let alive = self.is_active(idx);
+        self.set_active(idx, !alive);
     }
 
-    fn live_neighbor_count(&self, row: u32, column: u32) -> u8 {
-        let mut count = 0;
-        let north = if row == 0 {
-            self.height - 1
+    fn is_active(&self, idx: usize) -> bool {
+        if self.state_count > 2 {
+            self.states[self.i][idx] == 1
         } else {
-            row - 1
-        };
+            self.cells[self.i].contains(idx)
+        }
+    }
 
-        let south = if row == self.height - 1 {
-            0
+    fn set_active(&mut self, idx: usize, alive: bool) {
+        if self.state_count > 2 {
+            self.states[self.i][idx] = if alive { 1 } else { 0 };
         } else {
-            row + 1
-        };
+            self.cells[self.i].set(idx, alive);
+        }
+    }
 
-        let west = if column == 0 {
-            self.width - 1
-        } else {
-            column - 1
-        };
+    /// Parses a rulestring such as `"B3/S23"` and installs it as the rule used by `tick`.
+    pub fn set_rule(&mut self, rule: &str) {
+        let (birth, survival) = Universe::parse_rule(rule);
+        self.birth = birth;
+        self.survival = survival;
+        self.state_count = 2;
+    }
 
-        let east = if column == self.width - 1 {
-            0
-        } else {
-            column + 1
+    /// Formats the current transition rule back into `B/S` notation.
+    pub fn rule(&self) -> String {
+        Universe::format_rule(self.birth, self.survival)
+    }
+
+    fn parse_rule(rule: &str) -> (u16, u16) {
+        let mut birth = 0u16;
+        let mut survival = 0u16;
+
+        for part in rule.to_ascii_uppercase().split('/') {
+            if let Some(digits) = part.strip_prefix('B') {
+                for c in digits.chars().filter_map(|c| c.to_digit(10)) {
+                    birth |= 1 << c;
+                }
+            } else if let Some(digits) = part.strip_prefix('S') {
+                for c in digits.chars().filter_map(|c| c.to_digit(10)) {
+                    survival |= 1 << c;
+                }
+            }
+        }
+
+        (birth, survival)
+    }
+
+    fn format_rule(birth: u16, survival: u16) -> String {
+        let digits = |mask: u16| {
+            (0..=8)
+                .filter(|n| (mask >> n) & 1 == 1)
+                .map(|n| n.to_string())
+                .join("")
         };
 
-        let cells = &self.cells[self.i];
-        let nw = self.get_index(north, west);
-        count += cells[nw] as u8;
+        format!("B{}/S{}", digits(birth), digits(survival))
+    }
 
-        let n = self.get_index(north, column);
-        count += cells[n] as u8;
+    /// Parses a Generations rulestring such as `"B2/S/C3"` and switches `tick` to the multi-state model.
+    pub fn set_generations_rule(&mut self, rule: &str) {
+        let (birth, survival, state_count) = Universe::parse_generations_rule(rule);
+        self.birth = birth;
+        self.survival = survival;
+
+        if state_count > 2 {
+            let len = (self.width * self.height) as usize;
+            let mut states = vec![0u8; len];
+            for idx in 0..len {
+                if self.cells[self.i].contains(idx) {
+                    states[idx] = 1;
+                }
+            }
+            self.states = [states, vec![0; len]];
+            self.i = 0;
+        }
 
-        let ne = self.get_index(north, east);
-        count += cells[ne] as u8;
+        self.state_count = state_count;
+    }
 
-        let w = self.get_index(row, west);
-        count += cells[w] as u8;
+    fn parse_generations_rule(rule: &str) -> (u16, u16, u16) {
+        let mut birth = 0u16;
+        let mut survival = 0u16;
+        let mut state_count = 2u16;
+
+        for part in rule.to_ascii_uppercase().split('/') {
+            if let Some(digits) = part.strip_prefix('B') {
+                for c in digits.chars().filter_map(|c| c.to_digit(10)) {
+                    birth |= 1 << c;
+                }
+            } else if let Some(digits) = part.strip_prefix('S') {
+                for c in digits.chars().filter_map(|c| c.to_digit(10)) {
+                    survival |= 1 << c;
+                }
+            } else if let Some(digits) = part.strip_prefix('C') {
+                if let Ok(c) = digits.parse::<u16>() {
+                    state_count = c.clamp(2, 256);
+                }
+            }
+        }
 
-        let e = self.get_index(row, east);
-        count += cells[e] as u8;
+        (birth, survival, state_count)
+    }
 
-        let sw = self.get_index(south, west);
-        count += cells[sw] as u8;
+    fn offset(&self, value: u32, delta: i32, len: u32) -> Option<u32> {
+        let next = value as i32 + delta;
+        if next >= 0 && next < len as i32 {
+            return Some(next as u32);
+        }
+        if self.auto_grow {
+            return None;
+        }
+        Some(((next + len as i32) % len as i32) as u32)
+    }
 
-        let s = self.get_index(south, column);
-        count += cells[s] as u8;
+    fn live_neighbor_count(&self, row: u32, column: u32) -> u8 {
+        let mut count = 0;
+        let cells = &self.cells[self.i];
 
-        let se = self.get_index(south, east);
-        count += cells[se] as u8;
+        for &dr in &[-1, 0, 1] {
+            for &dc in &[-1, 0, 1] {
+                if dr == 0 && dc == 0 {
+                    continue;
+                }
+                let r = self.offset(row, dr, self.height);
+                let c = self.offset(column, dc, self.width);
+                if let (Some(r), Some(c)) = (r, c) {
+                    count += cells[self.get_index(r, c)] as u8;
+                }
+            }
+        }
+
+        count
+    }
+
+    fn live_neighbor_count_generations(&self, row: u32, column: u32) -> u8 {
+        let mut count = 0;
+        let states = &self.states[self.i];
+
+        for &dr in &[-1, 0, 1] {
+            for &dc in &[-1, 0, 1] {
+                if dr == 0 && dc == 0 {
+                    continue;
+                }
+                let r = self.offset(row, dr, self.height);
+                let c = self.offset(column, dc, self.width);
+                if let (Some(r), Some(c)) = (r, c) {
+                    if states[self.get_index(r, c)] == 1 {
+                        count += 1;
+                    }
+                }
+            }
+        }
 
         count
     }
 
+    fn tick_generations(&mut self) {
+        let new_i = (self.i + 1) % 2;
+        let state_count = self.state_count;
+
+        for row in 0..self.height {
+            for col in 0..self.width {
+                let idx = self.get_index(row, col);
+                let state = self.states[self.i][idx];
+                let next = if state == 0 {
+                    let n = self.live_neighbor_count_generations(row, col);
+                    if (self.birth >> n) & 1 == 1 { 1 } else { 0 }
+                } else if state == 1 {
+                    let n = self.live_neighbor_count_generations(row, col);
+                    if (self.survival >> n) & 1 == 1 { 1 } else { 2 }
+                } else {
+                    let advanced = state as u16 + 1;
+                    if advanced >= state_count { 0 } else { advanced as u8 }
+                };
+                self.states[new_i][idx] = next;
+                self.ages[idx] = if next == 1 { self.ages[idx].saturating_add(1) } else { 0 };
+            }
+        }
+
+        self.i = new_i;
+    }
+
     pub fn tick(&mut self) {
         let _timer = Timer::new("Universe::tick");
+
+        if self.state_count > 2 {
+            self.tick_generations();
+            return;
+        }
+
+        if self.tick_swar() {
+            return;
+        }
+
+        self.tick_scalar();
+    }
+
+    fn tick_scalar(&mut self) {
         let new_i = (self.i + 1) % 2;
         for row in 0..self.height {
             for col in 0..self.width {
                 let idx = self.get_index(row, col);
                 let cell = self.cells[self.i].contains(idx);
-                let live_neighbors = self.live_neighbor_count(row, col);
-                let next_cell = match (cell, live_neighbors) {
-                    (true, x) if x < 2 => false,
-                    (true, 2) | (true, 3) => true,
-                    (true, x) if x > 3 => false,
-                    (false, 3) => true,
-                    (otherwise, _) => otherwise,
+                let n = self.live_neighbor_count(row, col);
+                let next = if cell {
+                    (self.survival >> n) & 1 == 1
+                } else {
+                    (self.birth >> n) & 1 == 1
                 };
-                self.cells[new_i].set(idx, next_cell);
+                self.cells[new_i].set(idx, next);
+                self.ages[idx] = if next { self.ages[idx].saturating_add(1) } else { 0 };
+            }
+        }
+
+        self.i = new_i;
+
+        if self.auto_grow {
+            self.grow_if_needed();
+        }
+    }
+
+    /// Turns auto-growing (non-toroidal) mode on or off.
+    pub fn set_auto_grow(&mut self, on: bool) {
+        self.auto_grow = on;
+    }
+
+    /// Buffer row at which row 0 of the original coordinate space now lies.
+    pub fn origin_row(&self) -> i32 {
+        self.origin_row
+    }
+
+    /// Buffer column at which column 0 of the original coordinate space now lies.
+    pub fn origin_col(&self) -> i32 {
+        self.origin_col
+    }
+
+    fn grow_if_needed(&mut self) {
+        const MARGIN: u32 = 16;
+
+        let cells = &self.cells[self.i];
+        let mut grow_top = false;
+        let mut grow_bottom = false;
+        let mut grow_left = false;
+        let mut grow_right = false;
+
+        for col in 0..self.width {
+            grow_top |= cells[self.get_index(0, col)];
+            grow_bottom |= cells[self.get_index(self.height - 1, col)];
+        }
+        for row in 0..self.height {
+            grow_left |= cells[self.get_index(row, 0)];
+            grow_right |= cells[self.get_index(row, self.width - 1)];
+        }
+
+        if !(grow_top || grow_bottom || grow_left || grow_right) {
+            return;
+        }
+
+        let top = if grow_top { MARGIN } else { 0 };
+        let left = if grow_left { MARGIN } else { 0 };
+        let new_width = self.width + left + if grow_right { MARGIN } else { 0 };
+        let new_height = self.height + top + if grow_bottom { MARGIN } else { 0 };
+
+        let mut new_cells = Universe::new_cells(new_width, new_height);
+        let mut new_ages = vec![0u32; (new_width * new_height) as usize];
+        for row in 0..self.height {
+            for col in 0..self.width {
+                let old_index = self.get_index(row, col);
+                let new_index = ((row + top) * new_width + (col + left)) as usize;
+                if self.cells[self.i].contains(old_index) {
+                    new_cells.set(new_index, true);
+                }
+                new_ages[new_index] = self.ages[old_index];
             }
         }
 
+        self.cells = [new_cells, Universe::new_cells(new_width, new_height)];
+        self.ages = new_ages;
+        self.width = new_width;
+        self.height = new_height;
+        self.i = 0;
+        self.origin_row += top as i32;
+        self.origin_col += left as i32;
+    }
+
+    // SWAR fast path for `tick`. Only handles widths that are a multiple of
+    // 32 in a toroidal (non-auto-growing) universe; returns `false` otherwise
+    // and leaves `tick` to fall back to `tick_scalar`.
+    fn tick_swar(&mut self) -> bool {
+        if self.auto_grow || self.width == 0 || !self.width.is_multiple_of(32) || self.height == 0 {
+            return false;
+        }
+
+        let words_per_row = (self.width / 32) as usize;
+        let height = self.height as usize;
+        let new_i = (self.i + 1) % 2;
+        let birth = self.birth;
+        let survival = self.survival;
+
+        let (src, dst) = if self.i == 0 {
+            let (a, b) = self.cells.split_at_mut(1);
+            (&a[0], &mut b[0])
+        } else {
+            let (a, b) = self.cells.split_at_mut(1);
+            (&b[0], &mut a[0])
+        };
+
+        let src_words = src.as_slice();
+        let dst_words = dst.as_mut_slice();
+
+        // Value of the neighbor one column west/east of word `w`, aligned
+        // back onto word `w`'s bit positions, wrapping to the row's other
+        // end word at the row boundary.
+        let west_of = |row_words: &[u32], w: usize| -> u32 {
+            let w_west = if w == 0 { words_per_row - 1 } else { w - 1 };
+            (row_words[w] << 1) | (row_words[w_west] >> 31)
+        };
+        let east_of = |row_words: &[u32], w: usize| -> u32 {
+            let w_east = if w == words_per_row - 1 { 0 } else { w + 1 };
+            (row_words[w] >> 1) | (row_words[w_east] << 31)
+        };
+
+        for row in 0..height {
+            let north = if row == 0 { height - 1 } else { row - 1 };
+            let south = if row == height - 1 { 0 } else { row + 1 };
+
+            let n_row = &src_words[north * words_per_row..(north + 1) * words_per_row];
+            let c_row = &src_words[row * words_per_row..(row + 1) * words_per_row];
+            let s_row = &src_words[south * words_per_row..(south + 1) * words_per_row];
+            let out_row = &mut dst_words[row * words_per_row..(row + 1) * words_per_row];
+
+            for w in 0..words_per_row {
+                // Horizontal west+center+east sum for the row above and
+                // below (0..=3), and west+east only for this row (0..=2,
+                // the center cell is handled separately as `center` below).
+                let top = add3_bits(west_of(n_row, w), n_row[w], east_of(n_row, w));
+                let bot = add3_bits(west_of(s_row, w), s_row[w], east_of(s_row, w));
+                let mid = add2_bits(west_of(c_row, w), east_of(c_row, w));
+
+                let count = add_planes(
+                    &add_planes(&[top.0, top.1], &[bot.0, bot.1]),
+                    &[mid.0, mid.1],
+                );
+
+                let center = c_row[w];
+                let mut born = 0u32;
+                let mut survive = 0u32;
+                for n in 0..=8u8 {
+                    let eq = eq_value(&count, n);
+                    if (birth >> n) & 1 == 1 {
+                        born |= eq;
+                    }
+                    if (survival >> n) & 1 == 1 {
+                        survive |= eq;
+                    }
+                }
+
+                out_row[w] = (!center & born) | (center & survive);
+            }
+        }
+
+        let width = self.width as usize;
+        for idx in 0..width * height {
+            let alive = (dst_words[idx / 32] >> (idx % 32)) & 1 == 1;
+            self.ages[idx] = if alive { self.ages[idx].saturating_add(1) } else { 0 };
+        }
+
         self.i = new_i;
+        true
     }
 
     pub fn empty(width: u32, height: u32) -> Universe {
-        log!("Creating an empty universe of width {} and height {}", width, height);
-        utils::set_panic_hook();
+        #[cfg(target_arch = "wasm32")]
+        {
+            log!("Creating an empty universe of width {} and height {}", width, height);
+            utils::set_panic_hook();
+        }
         let cells = [
             Universe::new_cells(width, height),
             Universe::new_cells(width, height)
         ];
-        Universe { width, height, cells, i: 0 }
+        Universe {
+            width,
+            height,
+            cells,
+            i: 0,
+            birth: DEFAULT_BIRTH,
+            survival: DEFAULT_SURVIVAL,
+            auto_grow: false,
+            origin_row: 0,
+            origin_col: 0,
+            ages: vec![0; (width * height) as usize],
+            state_count: 2,
+            states: [Vec::new(), Vec::new()],
+        }
     }
 
     fn new_cells(width: u32, height: u32) -> FixedBitSet {
@@ -235,11 +621,144 @@ impl Universe {
                 let img_index = (r * width + c) as usize;
                 let value = img[img_index];
                 let index = self.get_index((row + r) % self.height, (col + c) % self.width);
-                self.cells[self.i].set(index, value)
+                self.set_active(index, value);
             }
         }
     }
 
+    /// Builds a new `Universe` from a Golly-compatible RLE document.
+    pub fn from_rle(rle: &str) -> Universe {
+        let (width, height, rule) =
+            Universe::parse_rle_header(rle).expect("invalid RLE: missing header line");
+        let mut universe = Universe::empty(width, height);
+        if let Some(rule) = rule {
+            universe.set_rule(&rule);
+        }
+        universe.load_rle(0, 0, rle);
+        universe
+    }
+
+    /// Decodes the run-length-encoded body of `rle` into the grid, offset by `(row, col)`.
+    pub fn load_rle(&mut self, row: u32, col: u32, rle: &str) {
+        let mut r = 0u32;
+        let mut c = 0u32;
+        let mut count = 0u32;
+
+        for ch in Universe::rle_body(rle).chars() {
+            match ch {
+                '0'..='9' => count = count * 10 + ch.to_digit(10).unwrap(),
+                'b' | 'o' | '$' => {
+                    let run = if count == 0 { 1 } else { count };
+                    count = 0;
+                    match ch {
+                        'o' => {
+                            for i in 0..run {
+                                let index = self
+                                    .get_index((row + r) % self.height, (col + c + i) % self.width);
+                                self.set_active(index, true);
+                            }
+                            c += run;
+                        }
+                        'b' => c += run,
+                        '$' => {
+                            r += run;
+                            c = 0;
+                        }
+                        _ => unreachable!(),
+                    }
+                }
+                '!' => break,
+                _ => {}
+            }
+        }
+    }
+
+    /// Encodes the live universe as a Golly-compatible RLE document.
+    pub fn to_rle(&self) -> String {
+        let mut body = String::new();
+        let mut blank_rows = 0u32;
+
+        for row in 0..self.height {
+            let mut row_tokens = String::new();
+            let mut col = 0u32;
+            while col < self.width {
+                let alive = self.is_active(self.get_index(row, col));
+                let mut run = 1;
+                while col + run < self.width
+                    && self.is_active(self.get_index(row, col + run)) == alive
+                {
+                    run += 1;
+                }
+                if alive {
+                    Universe::push_run(&mut row_tokens, run, 'o');
+                } else if col + run < self.width {
+                    Universe::push_run(&mut row_tokens, run, 'b');
+                }
+                col += run;
+            }
+
+            if row_tokens.is_empty() {
+                blank_rows += 1;
+                continue;
+            }
+            // Before the first emitted row, each blank row needs one `$`; after
+            // it, one more `$` ends the previously emitted row.
+            let row_ends = if body.is_empty() { blank_rows } else { blank_rows + 1 };
+            if row_ends > 0 {
+                Universe::push_run(&mut body, row_ends, '$');
+            }
+            blank_rows = 0;
+            body.push_str(&row_tokens);
+        }
+        body.push('!');
+
+        format!(
+            "x = {}, y = {}, rule = {}\n{}",
+            self.width,
+            self.height,
+            self.rule(),
+            body
+        )
+    }
+
+    fn push_run(out: &mut String, run: u32, tag: char) {
+        if run > 1 {
+            out.push_str(&run.to_string());
+        }
+        out.push(tag);
+    }
+
+    fn parse_rle_header(rle: &str) -> Option<(u32, u32, Option<String>)> {
+        let header = rle.lines().find(|line| !line.trim_start().starts_with('#'))?;
+
+        let mut width = None;
+        let mut height = None;
+        let mut rule = None;
+
+        for field in header.split(',') {
+            let mut parts = field.splitn(2, '=');
+            let key = parts.next()?.trim();
+            let value = parts.next()?.trim();
+            match key {
+                "x" => width = value.parse().ok(),
+                "y" => height = value.parse().ok(),
+                "rule" => rule = Some(value.to_string()),
+                _ => {}
+            }
+        }
+
+        Some((width?, height?, rule))
+    }
+
+    fn rle_body(rle: &str) -> String {
+        rle.lines()
+            .filter(|line| !line.trim_start().starts_with('#'))
+            .skip_while(|line| !line.contains('='))
+            .skip(1)
+            .collect::<Vec<_>>()
+            .join("")
+    }
+
     pub fn render(&self) -> String {
         return self.to_string();
     }
@@ -253,8 +772,29 @@ impl Universe {
     pub fn set_cells(&mut self, cells: &[(u32, u32)]) {
         for (row, col) in cells.iter().cloned() {
             let idx = self.get_index(row, col);
-            self.cells[self.i].set(idx, true);
+            self.set_active(idx, true);
+        }
+    }
+
+    /// Renders the universe with live cells ANSI-colored by age.
+    pub fn render_colored(&self) -> String {
+        let mut out = String::new();
+        for line in &(0..((self.width * self.height) as usize)).chunks(self.width as usize) {
+            for index in line {
+                if self.is_active(index) {
+                    let color = match self.ages[index] {
+                        0..=1 => 32,  // green: newborn
+                        2..=10 => 33, // yellow: young
+                        _ => 34,      // blue: long-lived/stable
+                    };
+                    out.push_str(&format!("\x1b[{}m◼\x1b[0m", color));
+                } else {
+                    out.push('◻');
+                }
+            }
+            out.push('\n');
         }
+        out
     }
 }
 
@@ -262,7 +802,7 @@ impl fmt::Display for Universe {
     fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
         for line in &(0..((self.width * self.height) as usize)).chunks(self.width as usize) {
             for index in line {
-                let symbol = if self.cells[self.i].contains(index) { '◼' } else { '◻' };
+                let symbol = if self.is_active(index) { '◼' } else { '◻' };
                 write!(f, "{}", symbol)?;
             }
             write!(f, "\n")?
@@ -270,3 +810,70 @@ impl fmt::Display for Universe {
         Ok(())
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn swar_tick_matches_scalar_tick() {
+        let mut swar = Universe::empty(64, 64);
+        let mut scalar = Universe::empty(64, 64);
+        for &(row, col) in &[(1, 1), (1, 2), (1, 3), (2, 1), (3, 2)] {
+            swar.toggle_cell(row, col);
+            scalar.toggle_cell(row, col);
+        }
+
+        for _ in 0..5 {
+            assert!(swar.tick_swar());
+            scalar.tick_scalar();
+            assert_eq!(swar.get_cells().as_slice(), scalar.get_cells().as_slice());
+            assert_eq!(swar.ages, scalar.ages);
+        }
+    }
+
+    #[test]
+    fn rule_round_trips_through_set_rule() {
+        let mut universe = Universe::empty(4, 4);
+        universe.set_rule("B36/S23");
+        assert_eq!(universe.rule(), "B36/S23");
+
+        universe.set_rule("b3/s23");
+        assert_eq!(universe.rule(), "B3/S23");
+    }
+
+    #[test]
+    fn rle_round_trip_preserves_pattern() {
+        let mut original = Universe::empty(8, 8);
+        original.add_glider(1, 1);
+
+        let rle = original.to_rle();
+        let restored = Universe::from_rle(&rle);
+
+        assert_eq!(restored.width(), original.width());
+        assert_eq!(restored.height(), original.height());
+        assert_eq!(restored.render(), original.render());
+    }
+
+    #[test]
+    fn generations_rule_migrates_cells_seeded_before_it_was_set() {
+        let mut universe = Universe::empty(8, 8);
+        universe.add_glider(1, 1);
+        universe.set_generations_rule("B3/S23/C3");
+
+        assert!(universe.states[universe.i].iter().any(|&s| s == 1));
+
+        for _ in 0..3 {
+            universe.tick_generations();
+        }
+
+        assert!(universe.states[universe.i].iter().any(|&s| s != 0));
+    }
+
+    #[test]
+    fn generations_rule_clamps_out_of_range_state_count() {
+        let mut universe = Universe::empty(4, 4);
+        universe.set_generations_rule("B2/S/C300");
+        assert_eq!(universe.state_count, 256);
+    }
+}